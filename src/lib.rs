@@ -26,12 +26,15 @@
 //! ```
 
 use rayon::{ThreadPoolBuilder, prelude::*};
+use std::collections::HashMap;
 use std::fmt;
 use std::process::Command;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Error type for rtranslate
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum TranslateError {
     CommandFailed(String),
     Utf8Error(String),
@@ -54,6 +57,23 @@ impl fmt::Display for TranslateError {
 
 impl std::error::Error for TranslateError {}
 
+/// The full result of a translation request.
+///
+/// Google splits multi-sentence input across several inner arrays in its response;
+/// `text` is all of them concatenated in order, while `segments` keeps each one
+/// separate in case a caller wants to work sentence-by-sentence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Translation {
+    pub text: String,
+    pub detected_source: Option<String>,
+    pub segments: Vec<String>,
+}
+
+/// Above this many bytes of URL-encoded text, `translate` splits the input
+/// into sentence-sized chunks instead of sending it in one request (Google
+/// silently fails or rate-limits long `q=` values).
+const CHUNK_THRESHOLD_BYTES: usize = 1500;
+
 /// Translate a single string.
 ///
 /// # Example
@@ -62,27 +82,292 @@ impl std::error::Error for TranslateError {}
 /// println!("Translated: {}", translated);
 /// ```
 pub fn translate(text: &str, from: &str, to: &str) -> Result<String, TranslateError> {
+    translate_with_transport(text, from, to, &CurlTransport).map(|t| t.text)
+}
+
+/// Fetches the raw `translate_a/single` response body for a URL.
+///
+/// Lets callers swap out how requests are made — the default
+/// [`CurlTransport`] spawns a `curl` child process per call, which is simple
+/// and dependency-free but forks a process for every translation in a batch.
+pub trait Transport: Send + Sync {
+    fn fetch(&self, url: &str) -> Result<String, TranslateError>;
+}
+
+/// Default transport: spawns a `curl` child process per request.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CurlTransport;
+
+impl Transport for CurlTransport {
+    fn fetch(&self, url: &str) -> Result<String, TranslateError> {
+        let output = Command::new("curl")
+            .arg("-s")
+            .arg(url)
+            .output()
+            .map_err(|e| TranslateError::CommandFailed(e.to_string()))?;
+
+        if !output.status.success() {
+            return Err(TranslateError::CommandFailed(format!(
+                "curl exited with: {:?}",
+                output.status.code()
+            )));
+        }
+
+        String::from_utf8(output.stdout).map_err(|e| TranslateError::Utf8Error(e.to_string()))
+    }
+}
+
+/// Transport backed by a pooled `reqwest` client, reusing keep-alive
+/// connections across an entire `translate_vec` call instead of spawning a
+/// process per request. Enabled via the `reqwest-transport` cargo feature.
+#[cfg(feature = "reqwest-transport")]
+#[derive(Debug)]
+pub struct ReqwestTransport {
+    client: reqwest::blocking::Client,
+}
+
+#[cfg(feature = "reqwest-transport")]
+impl ReqwestTransport {
+    pub fn new() -> Self {
+        ReqwestTransport {
+            client: reqwest::blocking::Client::new(),
+        }
+    }
+}
+
+#[cfg(feature = "reqwest-transport")]
+impl Default for ReqwestTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "reqwest-transport")]
+impl Transport for ReqwestTransport {
+    fn fetch(&self, url: &str) -> Result<String, TranslateError> {
+        self.client
+            .get(url)
+            .send()
+            .and_then(|resp| resp.text())
+            .map_err(|e| TranslateError::CommandFailed(e.to_string()))
+    }
+}
+
+/// Translate long input by splitting it into chunks under
+/// [`CHUNK_THRESHOLD_BYTES`], translating each chunk in parallel through
+/// `transport`, and reassembling the results in original order.
+///
+/// Leading/trailing whitespace on the original text is preserved; chunk seams
+/// are rejoined with a single space. Segments from every chunk are
+/// concatenated in order; the detected source language is taken from the
+/// first chunk that reports one, since all chunks come from the same input text.
+fn translate_chunked_with_transport(
+    text: &str,
+    from: &str,
+    to: &str,
+    transport: &impl Transport,
+) -> Result<Translation, TranslateError> {
+    let leading_ws: String = text.chars().take_while(|c| c.is_whitespace()).collect();
+    let trailing_ws: String = text
+        .chars()
+        .rev()
+        .take_while(|c| c.is_whitespace())
+        .collect::<Vec<char>>()
+        .into_iter()
+        .rev()
+        .collect();
+
+    let chunks = split_into_chunks(text.trim(), CHUNK_THRESHOLD_BYTES);
+
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build()
+        .expect("Failed to create thread pool");
+
+    let results: Vec<Result<Translation, TranslateError>> = pool.install(|| {
+        chunks
+            .par_iter()
+            .map(|chunk| translate_request(chunk, from, to, transport))
+            .collect()
+    });
+
+    let mut reassembled = String::new();
+    let mut segments = Vec::new();
+    let mut detected_source = None;
+    for (i, result) in results.into_iter().enumerate() {
+        let translation = result?;
+        if i > 0 {
+            reassembled.push(' ');
+        }
+        reassembled.push_str(&translation.text);
+        segments.extend(translation.segments);
+        if detected_source.is_none() {
+            detected_source = translation.detected_source;
+        }
+    }
+
+    Ok(Translation {
+        text: format!("{}{}{}", leading_ws, reassembled, trailing_ws),
+        detected_source,
+        segments,
+    })
+}
+
+/// Split `text` into chunks that each stay under `max_encoded_len` bytes once
+/// URL-encoded, breaking greedily at sentence boundaries (`.`, `!`, `?`, `。`,
+/// newline) and never splitting mid-sentence unless a single sentence alone
+/// exceeds the limit, in which case it falls back to a whitespace split.
+fn split_into_chunks(text: &str, max_encoded_len: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+
+    for sentence in split_sentences(text) {
+        let candidate = if current.is_empty() {
+            sentence.clone()
+        } else {
+            format!("{} {}", current, sentence)
+        };
+
+        if url_encode(&candidate).len() <= max_encoded_len {
+            current = candidate;
+            continue;
+        }
+
+        if !current.is_empty() {
+            chunks.push(std::mem::take(&mut current));
+        }
+
+        if url_encode(&sentence).len() <= max_encoded_len {
+            current = sentence;
+        } else {
+            chunks.extend(split_by_whitespace(&sentence, max_encoded_len));
+        }
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+/// Split `text` into sentences on `.`, `!`, `?`, `。`, and newline, keeping a
+/// punctuation terminator attached to the sentence it closes. Each sentence is
+/// trimmed, so a newline terminator is dropped.
+fn split_sentences(text: &str) -> Vec<String> {
+    let mut sentences = Vec::new();
+    let mut current = String::new();
+
+    for ch in text.chars() {
+        current.push(ch);
+        if matches!(ch, '.' | '!' | '?' | '。' | '\n') {
+            let trimmed = current.trim();
+            if !trimmed.is_empty() {
+                sentences.push(trimmed.to_string());
+            }
+            current.clear();
+        }
+    }
+
+    let trimmed = current.trim();
+    if !trimmed.is_empty() {
+        sentences.push(trimmed.to_string());
+    }
+
+    sentences
+}
+
+/// Fallback splitter for a single sentence that alone exceeds
+/// `max_encoded_len`: packs whitespace-separated words into chunks instead.
+fn split_by_whitespace(text: &str, max_encoded_len: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+
+    for word in text.split_whitespace() {
+        let candidate = if current.is_empty() {
+            word.to_string()
+        } else {
+            format!("{} {}", current, word)
+        };
+
+        if url_encode(&candidate).len() <= max_encoded_len {
+            current = candidate;
+        } else {
+            if !current.is_empty() {
+                chunks.push(std::mem::take(&mut current));
+            }
+            current = word.to_string();
+        }
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+/// Translate a single string, returning the detected source language and the
+/// individual sentence segments alongside the fully reassembled text.
+///
+/// # Example
+/// ```
+/// let result = rtranslate::translate_detailed("Hello world", "auto", "vi").unwrap();
+/// println!("Translated: {} (from {:?})", result.text, result.detected_source);
+/// ```
+pub fn translate_detailed(
+    text: &str,
+    from: &str,
+    to: &str,
+) -> Result<Translation, TranslateError> {
+    translate_with_transport(text, from, to, &CurlTransport)
+}
+
+/// Translate a single string using an explicit [`Transport`] instead of the
+/// default [`CurlTransport`].
+///
+/// Long input is chunked the same way as [`translate`], with every chunk
+/// routed through `transport` — adopting a custom transport does not lose the
+/// long-input safety net.
+///
+/// # Example
+///
+/// ```
+/// let result = rtranslate::translate_with_transport(
+///     "Hello world",
+///     "auto",
+///     "vi",
+///     &rtranslate::CurlTransport,
+/// );
+/// ```
+pub fn translate_with_transport(
+    text: &str,
+    from: &str,
+    to: &str,
+    transport: &impl Transport,
+) -> Result<Translation, TranslateError> {
+    if url_encode(text).len() > CHUNK_THRESHOLD_BYTES {
+        return translate_chunked_with_transport(text, from, to, transport);
+    }
+    translate_request(text, from, to, transport)
+}
+
+/// Fetch and parse a single `translate_a/single` request through `transport`,
+/// with no chunking. Shared by [`translate_with_transport`] and
+/// [`translate_chunked_with_transport`] (per chunk).
+fn translate_request(
+    text: &str,
+    from: &str,
+    to: &str,
+    transport: &impl Transport,
+) -> Result<Translation, TranslateError> {
     let q = url_encode(text);
     let url = format!(
         "https://translate.googleapis.com/translate_a/single?client=gtx&sl={}&tl={}&dt=t&q={}",
         from, to, q
     );
 
-    let output = Command::new("curl")
-        .arg("-s")
-        .arg(&url)
-        .output()
-        .map_err(|e| TranslateError::CommandFailed(e.to_string()))?;
-
-    if !output.status.success() {
-        return Err(TranslateError::CommandFailed(format!(
-            "curl exited with: {:?}",
-            output.status.code()
-        )));
-    }
-
-    let body =
-        String::from_utf8(output.stdout).map_err(|e| TranslateError::Utf8Error(e.to_string()))?;
+    let body = transport.fetch(&url)?;
 
     if body.trim().is_empty() {
         return Err(TranslateError::EmptyResponse);
@@ -93,7 +378,7 @@ pub fn translate(text: &str, from: &str, to: &str) -> Result<String, TranslateEr
         return Err(TranslateError::RateLimited);
     }
 
-    parse_translation(&body)
+    parse_translation_detailed(&body)
 }
 
 /// Convenience function: translate multiple strings with **default 4 threads**.
@@ -155,21 +440,494 @@ pub fn translate_vec_with_threads(
     })
 }
 
-fn parse_translation(body: &str) -> Result<String, TranslateError> {
-    if let Some(start) = body.find("[[[\"") {
-        let after = &body[start + 4..];
-        if let Some(end) = after.find('"') {
-            let translated = &after[..end];
-            if translated.trim().is_empty() {
-                return Err(TranslateError::EmptyResponse);
+/// In-memory cache that deduplicates repeated `(text, from, to)` translation
+/// requests, keyed by the exact triple.
+///
+/// Real batches often contain duplicate strings (repeated UI labels,
+/// boilerplate); consulting the cache first means a 1000-element batch with
+/// 50 unique strings only makes 50 network calls.
+#[derive(Debug, Default)]
+pub struct TranslationCache {
+    entries: Mutex<HashMap<(String, String, String), String>>,
+}
+
+impl TranslationCache {
+    pub fn new() -> Self {
+        TranslationCache::default()
+    }
+
+    fn get(&self, key: &(String, String, String)) -> Option<String> {
+        self.entries.lock().unwrap().get(key).cloned()
+    }
+
+    fn insert(&self, key: (String, String, String), value: String) {
+        self.entries.lock().unwrap().insert(key, value);
+    }
+}
+
+/// Translate multiple strings, consulting `cache` first and only dispatching
+/// unique cache-misses through the rayon pool. Successful translations are
+/// written back to the cache for future calls.
+///
+/// # Example
+///
+/// ```
+/// let cache = rtranslate::TranslationCache::new();
+/// let phrases = ["Good morning", "Good morning", "Rust is great"];
+/// let results = rtranslate::translate_vec_cached(&phrases, "auto", "vi", &cache);
+/// ```
+pub fn translate_vec_cached(
+    texts: &[&str],
+    from: &str,
+    to: &str,
+    cache: &TranslationCache,
+) -> Vec<Result<String, TranslateError>> {
+    let key_for = |text: &str| (text.to_string(), from.to_string(), to.to_string());
+
+    let mut unique_misses: Vec<&str> = Vec::new();
+    for &text in texts {
+        if cache.get(&key_for(text)).is_none() && !unique_misses.contains(&text) {
+            unique_misses.push(text);
+        }
+    }
+
+    let fresh = translate_vec(&unique_misses, from, to);
+    let mut fresh_results: HashMap<&str, Result<String, TranslateError>> = HashMap::new();
+    for (&text, result) in unique_misses.iter().zip(fresh) {
+        if let Ok(translated) = &result {
+            cache.insert(key_for(text), translated.clone());
+        }
+        fresh_results.insert(text, result);
+    }
+
+    texts
+        .iter()
+        .map(|&text| {
+            cache
+                .get(&key_for(text))
+                .map(Ok)
+                .or_else(|| fresh_results.get(text).cloned())
+                .unwrap_or(Err(TranslateError::EmptyResponse))
+        })
+        .collect()
+}
+
+/// Translate one input into several target languages with **default 4 threads**.
+///
+/// Returns each target language code paired with its translation result, in
+/// the same order as `targets`.
+///
+/// # Example
+///
+/// ```
+/// let results = rtranslate::translate_multi("Hello", "auto", &["vi", "ja", "fr"]);
+/// for (target, result) in results {
+///     println!("{}: {:?}", target, result);
+/// }
+/// ```
+pub fn translate_multi(
+    text: &str,
+    from: &str,
+    targets: &[&str],
+) -> Vec<(String, Result<String, TranslateError>)> {
+    translate_multi_with_threads(text, from, targets, 4)
+}
+
+/// Translate one input into several target languages in parallel with a
+/// configurable number of threads.
+///
+/// Reuses the same thread pool machinery as [`translate_vec_with_threads`], but
+/// fans one source text out across `targets` instead of fanning many texts out
+/// to one target.
+pub fn translate_multi_with_threads(
+    text: &str,
+    from: &str,
+    targets: &[&str],
+    num_threads: usize,
+) -> Vec<(String, Result<String, TranslateError>)> {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()
+        .expect("Failed to create thread pool");
+
+    let targets = Arc::new(targets.to_vec());
+
+    pool.install(|| {
+        targets
+            .par_iter()
+            .map(|&target| (target.to_string(), translate(text, from, target)))
+            .collect()
+    })
+}
+
+/// Translate several inputs into several target languages, building a full
+/// localization table in one shot.
+///
+/// Returns one row per entry in `texts`, each row holding the target/result
+/// pairs produced by [`translate_multi`] for that text.
+///
+/// # Example
+///
+/// ```
+/// let table = rtranslate::translate_matrix(&["Hello", "Goodbye"], "auto", &["vi", "ja"]);
+/// for row in table {
+///     for (target, result) in row {
+///         println!("{}: {:?}", target, result);
+///     }
+/// }
+/// ```
+pub fn translate_matrix(
+    texts: &[&str],
+    from: &str,
+    targets: &[&str],
+) -> Vec<Vec<(String, Result<String, TranslateError>)>> {
+    texts
+        .iter()
+        .map(|text| translate_multi(text, from, targets))
+        .collect()
+}
+
+/// Translate multiple strings in parallel, threading a single shared
+/// [`Transport`] through the rayon closure so all `num_threads` workers reuse
+/// it (e.g. one pooled `reqwest` client) instead of spawning a process per
+/// translation.
+pub fn translate_vec_with_transport(
+    texts: &[&str],
+    from: &str,
+    to: &str,
+    num_threads: usize,
+    transport: &impl Transport,
+) -> Vec<Result<String, TranslateError>> {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()
+        .expect("Failed to create thread pool");
+
+    pool.install(|| {
+        texts
+            .par_iter()
+            .map(|text| translate_with_transport(text, from, to, transport).map(|t| t.text))
+            .collect()
+    })
+}
+
+/// Retry policy for [`translate_with_retry`] and [`translate_vec_with_threads_retry`].
+///
+/// On a retryable error, the delay before the next attempt is
+/// `min(max_delay_ms, base_delay_ms * 2^attempt)`, optionally randomized up to
+/// that computed delay when `jitter` is set.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryConfig {
+    pub max_retries: u32,
+    pub base_delay_ms: u64,
+    pub max_delay_ms: u64,
+    pub jitter: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        RetryConfig {
+            max_retries: 3,
+            base_delay_ms: 250,
+            max_delay_ms: 5_000,
+            jitter: true,
+        }
+    }
+}
+
+/// Translate a single string, retrying with exponential backoff on
+/// [`TranslateError::RateLimited`], [`TranslateError::EmptyResponse`], or
+/// [`TranslateError::CommandFailed`].
+///
+/// # Example
+///
+/// ```
+/// let config = rtranslate::RetryConfig::default();
+/// let result = rtranslate::translate_with_retry("Hello", "auto", "vi", &config);
+/// ```
+pub fn translate_with_retry(
+    text: &str,
+    from: &str,
+    to: &str,
+    config: &RetryConfig,
+) -> Result<String, TranslateError> {
+    let mut attempt = 0;
+    loop {
+        match translate(text, from, to) {
+            Ok(result) => return Ok(result),
+            Err(err) if attempt < config.max_retries && is_retryable(&err) => {
+                thread::sleep(Duration::from_millis(backoff_delay(config, attempt)));
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+fn is_retryable(err: &TranslateError) -> bool {
+    matches!(
+        err,
+        TranslateError::RateLimited | TranslateError::EmptyResponse | TranslateError::CommandFailed(_)
+    )
+}
+
+fn backoff_delay(config: &RetryConfig, attempt: u32) -> u64 {
+    let exponential = config
+        .base_delay_ms
+        .saturating_mul(1u64 << attempt.min(32));
+    let delay = exponential.min(config.max_delay_ms);
+
+    if config.jitter { jittered(delay) } else { delay }
+}
+
+/// Return a pseudo-random value in `0..=max`, seeded from the current time.
+/// Good enough to spread out retries without pulling in a `rand` dependency.
+fn jittered(max: u64) -> u64 {
+    if max == 0 {
+        return 0;
+    }
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos() as u64)
+        .unwrap_or(0);
+
+    nanos % (max + 1)
+}
+
+/// Translate multiple strings in parallel with **default 4 threads**, retrying
+/// each one individually on transient failures.
+pub fn translate_vec_with_retry(
+    texts: &[&str],
+    from: &str,
+    to: &str,
+    config: &RetryConfig,
+) -> Vec<Result<String, TranslateError>> {
+    translate_vec_with_threads_retry(texts, from, to, 4, config)
+}
+
+/// Translate multiple strings in parallel with a configurable number of
+/// threads, retrying each one individually on transient failures so a batch
+/// job degrades gracefully instead of returning a wall of `RateLimited` errors.
+pub fn translate_vec_with_threads_retry(
+    texts: &[&str],
+    from: &str,
+    to: &str,
+    num_threads: usize,
+    config: &RetryConfig,
+) -> Vec<Result<String, TranslateError>> {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()
+        .expect("Failed to create thread pool");
+
+    let texts = Arc::new(texts.to_vec());
+
+    pool.install(|| {
+        texts
+            .par_iter()
+            .map(|text| translate_with_retry(text, from, to, config))
+            .collect()
+    })
+}
+
+/// Pipe a translation through a chain of languages, e.g. `["en", "ja", "de",
+/// "en"]` translates en→ja, then ja→de, then de→en, returning the final text.
+///
+/// Useful for the "round-trip garble" use case (translate-and-back to see
+/// semantic drift, or deliberately degrade text through many hops). The first
+/// entry may be `"auto"` to have the first hop detect the source language;
+/// every later entry is the target of one hop as well as the source of the
+/// next, so it must be a real language code.
+/// Each hop feeds the next the fully-reassembled text from
+/// [`translate_detailed`], not just its first sentence.
+///
+/// # Example
+///
+/// ```
+/// let result = rtranslate::translate_chain("Hello world", &["en", "ja", "en"]);
+/// ```
+pub fn translate_chain(text: &str, langs: &[&str]) -> Result<String, TranslateError> {
+    if langs.len() < 2 {
+        return Err(TranslateError::ParseError(
+            "translate_chain requires at least two languages".to_string(),
+        ));
+    }
+
+    let mut current = text.to_string();
+    for pair in langs.windows(2) {
+        current = translate_detailed(&current, pair[0], pair[1])?.text;
+    }
+
+    Ok(current)
+}
+
+/// Parse a raw `translate_a/single` response body into a [`Translation`].
+///
+/// The response looks like
+/// `[[["Xin chào","Hello",null,null,3,null,null,[[]]],["Thế giới","world",...]],null,"en"]`:
+/// a top-level array whose first element is an array of per-sentence tuples
+/// (`["<translated>","<original>",...]`), and whose third element is the
+/// detected source language code.
+fn parse_translation_detailed(body: &str) -> Result<Translation, TranslateError> {
+    let parse_error = || {
+        TranslateError::ParseError(format!(
+            "Unexpected response format: {}",
+            &body[..body.len().min(120)]
+        ))
+    };
+
+    let (value, _) = parse_json(body).map_err(|_| parse_error())?;
+    let top = match &value {
+        JsonValue::Array(items) => items,
+        _ => return Err(parse_error()),
+    };
+
+    let sentences = match top.first() {
+        Some(JsonValue::Array(items)) => items,
+        _ => return Err(parse_error()),
+    };
+
+    let mut segments = Vec::new();
+    for sentence in sentences {
+        let JsonValue::Array(parts) = sentence else {
+            continue;
+        };
+        if let Some(JsonValue::Str(translated)) = parts.first() {
+            segments.push(translated.clone());
+        }
+    }
+
+    if segments.is_empty() {
+        return Err(TranslateError::EmptyResponse);
+    }
+
+    let detected_source = match top.get(2) {
+        Some(JsonValue::Str(lang)) => Some(lang.clone()),
+        _ => None,
+    };
+
+    Ok(Translation {
+        text: segments.concat(),
+        detected_source,
+        segments,
+    })
+}
+
+/// A minimal JSON value, just enough to pick apart Google's response shape
+/// without pulling in a JSON dependency.
+#[derive(Debug, Clone, PartialEq)]
+enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    Str(String),
+    Array(Vec<JsonValue>),
+}
+
+/// Parse a JSON value starting at the beginning of `input`, returning it along
+/// with the remaining unparsed text.
+fn parse_json(input: &str) -> Result<(JsonValue, &str), ()> {
+    let input = input.trim_start();
+    match input.as_bytes().first() {
+        Some(b'[') => parse_json_array(input),
+        Some(b'"') => parse_json_string(input).map(|(s, rest)| (JsonValue::Str(s), rest)),
+        Some(b'n') if input.starts_with("null") => Ok((JsonValue::Null, &input[4..])),
+        Some(b't') if input.starts_with("true") => Ok((JsonValue::Bool(true), &input[4..])),
+        Some(b'f') if input.starts_with("false") => Ok((JsonValue::Bool(false), &input[5..])),
+        Some(c) if c.is_ascii_digit() || *c == b'-' => parse_json_number(input),
+        _ => Err(()),
+    }
+}
+
+fn parse_json_array(input: &str) -> Result<(JsonValue, &str), ()> {
+    let mut rest = input.strip_prefix('[').ok_or(())?.trim_start();
+    let mut items = Vec::new();
+
+    if let Some(after) = rest.strip_prefix(']') {
+        return Ok((JsonValue::Array(items), after));
+    }
+
+    loop {
+        let (value, after_value) = parse_json(rest)?;
+        items.push(value);
+        rest = after_value.trim_start();
+
+        match rest.as_bytes().first() {
+            Some(b',') => rest = rest[1..].trim_start(),
+            Some(b']') => return Ok((JsonValue::Array(items), &rest[1..])),
+            _ => return Err(()),
+        }
+    }
+}
+
+fn parse_json_string(input: &str) -> Result<(String, &str), ()> {
+    let mut chars = input.strip_prefix('"').ok_or(())?.char_indices().peekable();
+    let mut out = String::new();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok((out, &input[1 + i + 1..])),
+            '\\' => match chars.next() {
+                Some((_, '"')) => out.push('"'),
+                Some((_, '\\')) => out.push('\\'),
+                Some((_, '/')) => out.push('/'),
+                Some((_, 'n')) => out.push('\n'),
+                Some((_, 't')) => out.push('\t'),
+                Some((_, 'r')) => out.push('\r'),
+                Some((_, 'b')) => out.push('\u{8}'),
+                Some((_, 'f')) => out.push('\u{c}'),
+                Some((_, 'u')) => out.push(parse_unicode_escape(&mut chars)?),
+                _ => return Err(()),
+            },
+            other => out.push(other),
+        }
+    }
+
+    Err(())
+}
+
+/// Decode a `\uXXXX` escape (already past the `u`), combining a UTF-16
+/// surrogate pair into a single code point if one follows immediately.
+fn parse_unicode_escape(
+    chars: &mut std::iter::Peekable<std::str::CharIndices<'_>>,
+) -> Result<char, ()> {
+    let high = parse_hex4(chars)?;
+
+    let code_point = if (0xD800..=0xDBFF).contains(&high) {
+        match (chars.next(), chars.next()) {
+            (Some((_, '\\')), Some((_, 'u'))) => {
+                let low = parse_hex4(chars)?;
+                if !(0xDC00..=0xDFFF).contains(&low) {
+                    return Err(());
+                }
+                0x10000 + (high - 0xD800) * 0x400 + (low - 0xDC00)
             }
-            return Ok(translated.to_string());
+            _ => return Err(()),
         }
+    } else {
+        high
+    };
+
+    char::from_u32(code_point).ok_or(())
+}
+
+/// Consume exactly four hex digits and return their value.
+fn parse_hex4(chars: &mut std::iter::Peekable<std::str::CharIndices<'_>>) -> Result<u32, ()> {
+    let mut value = 0u32;
+    for _ in 0..4 {
+        let (_, c) = chars.next().ok_or(())?;
+        value = value * 16 + c.to_digit(16).ok_or(())?;
     }
-    Err(TranslateError::ParseError(format!(
-        "Unexpected response format: {}",
-        &body[..body.len().min(120)]
-    )))
+    Ok(value)
+}
+
+fn parse_json_number(input: &str) -> Result<(JsonValue, &str), ()> {
+    let end = input
+        .find(|c: char| !(c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E')))
+        .unwrap_or(input.len());
+    let (digits, rest) = input.split_at(end);
+    digits.parse::<f64>().map(|n| (JsonValue::Number(n), rest)).map_err(|_| ())
 }
 
 fn url_encode(input: &str) -> String {
@@ -188,22 +946,196 @@ fn url_encode(input: &str) -> String {
 mod tests {
     use super::*;
 
+    /// Transport stub that serves canned response bodies keyed by the exact
+    /// request URL, so parsing and fan-out can be exercised without a live
+    /// network call.
+    struct FakeTransport {
+        responses: HashMap<String, String>,
+    }
+
+    impl Transport for FakeTransport {
+        fn fetch(&self, url: &str) -> Result<String, TranslateError> {
+            self.responses
+                .get(url)
+                .cloned()
+                .ok_or_else(|| TranslateError::CommandFailed(format!("no fake response for {url}")))
+        }
+    }
+
+    fn translate_url(from: &str, to: &str, text: &str) -> String {
+        format!(
+            "https://translate.googleapis.com/translate_a/single?client=gtx&sl={}&tl={}&dt=t&q={}",
+            from,
+            to,
+            url_encode(text)
+        )
+    }
+
+    /// Transport stub that always returns a fixed response, counting how many
+    /// times it was called.
+    struct CountingTransport {
+        calls: Mutex<u32>,
+    }
+
+    impl Transport for CountingTransport {
+        fn fetch(&self, _url: &str) -> Result<String, TranslateError> {
+            *self.calls.lock().unwrap() += 1;
+            Ok(r#"[[["x","y",null,null,3,null,null,[[]]]],null,"en"]"#.to_string())
+        }
+    }
+
+    #[test]
+    fn test_translate_with_transport_parses_fake_response() {
+        let mut responses = HashMap::new();
+        responses.insert(
+            translate_url("auto", "vi", "Hello"),
+            r#"[[["Xin chào","Hello",null,null,3,null,null,[[]]]],null,"en"]"#.to_string(),
+        );
+        let transport = FakeTransport { responses };
+
+        let result = translate_with_transport("Hello", "auto", "vi", &transport).unwrap();
+        assert_eq!(result.text, "Xin chào");
+        assert_eq!(result.detected_source, Some("en".to_string()));
+    }
+
+    #[test]
+    fn test_translate_vec_with_transport_fans_out_in_order() {
+        let mut responses = HashMap::new();
+        responses.insert(
+            translate_url("auto", "vi", "Hello"),
+            r#"[[["Xin chào","Hello",null,null,3,null,null,[[]]]],null,"en"]"#.to_string(),
+        );
+        responses.insert(
+            translate_url("auto", "vi", "Bye"),
+            r#"[[["Tạm biệt","Bye",null,null,3,null,null,[[]]]],null,"en"]"#.to_string(),
+        );
+        let transport = FakeTransport { responses };
+
+        let results = translate_vec_with_transport(&["Hello", "Bye"], "auto", "vi", 2, &transport);
+        assert_eq!(results[0].as_ref().unwrap(), "Xin chào");
+        assert_eq!(results[1].as_ref().unwrap(), "Tạm biệt");
+    }
+
+    #[test]
+    fn test_translate_with_transport_chunks_long_input() {
+        let long_text = "word ".repeat(400);
+        let transport = CountingTransport {
+            calls: Mutex::new(0),
+        };
+
+        let result = translate_with_transport(&long_text, "auto", "vi", &transport).unwrap();
+
+        assert!(!result.text.is_empty());
+        assert!(
+            *transport.calls.lock().unwrap() > 1,
+            "long input routed through a custom transport should still be chunked across multiple fetch() calls"
+        );
+    }
+
     #[test]
     fn test_url_encode_basic() {
         assert_eq!(url_encode("Hello world!"), "Hello%20world%21");
     }
 
     #[test]
-    fn test_parse_translation_valid() {
+    fn test_parse_translation_detailed_valid() {
         let json = r#"[[["Xin chào","Hello",null,null,3,null,null,[[]]]],null,"en"]"#;
-        let result = parse_translation(json).unwrap();
-        assert_eq!(result, "Xin chào");
+        let result = parse_translation_detailed(json).unwrap();
+        assert_eq!(result.text, "Xin chào");
+        assert_eq!(result.segments, vec!["Xin chào".to_string()]);
+        assert_eq!(result.detected_source, Some("en".to_string()));
     }
 
     #[test]
-    fn test_parse_translation_invalid() {
+    fn test_parse_translation_detailed_multi_sentence() {
+        let json = r#"[[["Xin chào","Hello",null,null,3,null,null,[[]]],["Thế giới","world",null,null,3,null,null,[[]]]],null,"en"]"#;
+        let result = parse_translation_detailed(json).unwrap();
+        assert_eq!(result.text, "Xin chàoThế giới");
+        assert_eq!(
+            result.segments,
+            vec!["Xin chào".to_string(), "Thế giới".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_parse_translation_detailed_unicode_escape() {
+        let json = "[[[\"caf\\u00e9\",\"cafe\",null,null,3,null,null,[[]]]],null,\"en\"]";
+        let result = parse_translation_detailed(json).unwrap();
+        assert_eq!(result.text, "café");
+    }
+
+    #[test]
+    fn test_parse_translation_detailed_surrogate_pair() {
+        let json = "[[[\"\\ud83d\\ude00\",\"grin\",null,null,3,null,null,[[]]]],null,\"en\"]";
+        let result = parse_translation_detailed(json).unwrap();
+        assert_eq!(result.text, "😀");
+    }
+
+    #[test]
+    fn test_parse_translation_detailed_invalid() {
         let json = "INVALID";
-        assert!(parse_translation(json).is_err());
+        assert!(parse_translation_detailed(json).is_err());
+    }
+
+    #[test]
+    fn test_split_into_chunks_stays_under_limit() {
+        let sentence = "word ".repeat(50);
+        let text = format!("{s}. {s}. {s}.", s = sentence.trim());
+        let chunks = split_into_chunks(&text, 80);
+        assert!(chunks.len() > 1);
+        for chunk in &chunks {
+            assert!(url_encode(chunk).len() <= 80);
+        }
+    }
+
+    #[test]
+    fn test_split_into_chunks_keeps_short_text_whole() {
+        let chunks = split_into_chunks("Hello world.", 1500);
+        assert_eq!(chunks, vec!["Hello world.".to_string()]);
+    }
+
+    #[test]
+    fn test_backoff_delay_caps_at_max() {
+        let config = RetryConfig {
+            max_retries: 5,
+            base_delay_ms: 100,
+            max_delay_ms: 300,
+            jitter: false,
+        };
+        assert_eq!(backoff_delay(&config, 0), 100);
+        assert_eq!(backoff_delay(&config, 1), 200);
+        assert_eq!(backoff_delay(&config, 2), 300);
+        assert_eq!(backoff_delay(&config, 10), 300);
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        assert!(is_retryable(&TranslateError::RateLimited));
+        assert!(is_retryable(&TranslateError::EmptyResponse));
+        assert!(!is_retryable(&TranslateError::ParseError("x".to_string())));
+    }
+
+    #[test]
+    fn test_translation_cache_get_returns_inserted_entry() {
+        let cache = TranslationCache::new();
+        cache.insert(
+            ("Hello".to_string(), "auto".to_string(), "vi".to_string()),
+            "Xin chào".to_string(),
+        );
+        assert_eq!(
+            cache.get(&("Hello".to_string(), "auto".to_string(), "vi".to_string())),
+            Some("Xin chào".to_string())
+        );
+        assert_eq!(
+            cache.get(&("Bye".to_string(), "auto".to_string(), "vi".to_string())),
+            None
+        );
+    }
+
+    #[test]
+    fn test_translate_chain_requires_two_languages() {
+        let err = translate_chain("Hello", &["en"]).unwrap_err();
+        assert!(matches!(err, TranslateError::ParseError(_)));
     }
 
     #[test]